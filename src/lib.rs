@@ -1,5 +1,31 @@
 use std::iter::FusedIterator;
 
+/// Computes `base.pow(exponent)`, returning `None` if the result overflows
+/// `usize`, instead of panicking (debug builds) or silently wrapping
+/// (release builds).
+fn checked_pow(base: usize, exponent: usize) -> Option<usize> {
+    match u32::try_from(exponent) {
+        Ok(exponent) => base.checked_pow(exponent),
+        // 0 and 1 raised to any positive power are themselves.
+        Err(_) if base <= 1 => Some(base),
+        Err(_) => None,
+    }
+}
+
+/// Computes the product of `lens`, returning `None` on overflow instead of
+/// panicking (debug builds) or silently wrapping (release builds).
+fn checked_product(lens: impl IntoIterator<Item = usize>) -> Option<usize> {
+    // A zero length makes the product zero even after the partial product
+    // has overflowed.
+    lens.into_iter().fold(Some(1usize), |acc, len| {
+        if len == 0 {
+            Some(0)
+        } else {
+            acc.and_then(|acc| acc.checked_mul(len))
+        }
+    })
+}
+
 /// Create a cartesian product of a given length from a given iterable.
 pub trait ProductWithRepeat<T> {
     fn product_with_repeat(&self, repeat: usize) -> Iter<T>;
@@ -7,10 +33,17 @@ pub trait ProductWithRepeat<T> {
 
 impl<T, A: AsRef<[T]>> ProductWithRepeat<T> for A {
     fn product_with_repeat(&self, repeat: usize) -> Iter<T> {
+        let items = self.as_ref();
+        // `back` is the true cardinality when it fits in a `usize`, or a
+        // sentinel of `usize::MAX` otherwise. The sentinel keeps forward
+        // iteration working lazily over products too large to size
+        // exactly; see `Iter::total`.
+        let back = checked_pow(items.len(), repeat).unwrap_or(usize::MAX);
         Iter {
-            items: self.as_ref(),
-            state: vec![0; repeat],
-            completed: false,
+            items,
+            repeat,
+            front: 0,
+            back,
         }
     }
 }
@@ -19,33 +52,44 @@ impl<T, A: AsRef<[T]>> ProductWithRepeat<T> for A {
 pub struct Iter<'a, T> {
     /// The items used for the product
     items: &'a [T],
-    /// The list of indices for the items being iterated over.
-    state: Vec<usize>,
-    /// Whether or not all items have been iterated over.
-    completed: bool,
+    /// The length of the tuples being produced.
+    repeat: usize,
+    /// The linear index of the next tuple `next()` will yield.
+    front: usize,
+    /// The linear index one past the last tuple `next_back()` will yield.
+    back: usize,
 }
 
-impl<T> Iter<'_, T> {
-    fn item_len(&self) -> usize {
-        self.state.len()
+impl<'a, T> Iter<'a, T> {
+    /// Decodes a linear index into the tuple it represents, treating it as a
+    /// mixed-radix counter with base `items.len()` and `repeat` digits.
+    fn decode(&self, mut index: usize) -> Vec<&'a T> {
+        let base = self.items.len();
+        let mut digits = vec![0; self.repeat];
+        for digit in digits.iter_mut().rev() {
+            *digit = index % base;
+            index /= base;
+        }
+        digits.into_iter().map(|i| &self.items[i]).collect()
     }
 
-    #[inline]
-    fn increment_state(&mut self) {
-        let mut carry = true;
-        for r in self.state.iter_mut().rev() {
-            // Increment current index
-            *r += 1;
-            if *r >= self.items.len() {
-                *r = 0;
-            } else {
-                carry = false;
-                break;
-            }
+    /// The total number of tuples in the full product, i.e.
+    /// `items.len().pow(repeat)` — or `None` if that doesn't fit in a
+    /// `usize`.
+    fn total(&self) -> Option<usize> {
+        checked_pow(self.items.len(), self.repeat)
+    }
+
+    /// Returns the `index`-th tuple of the full product, without consuming
+    /// the iterator. Useful for sampling or sharding the product across
+    /// threads by index range.
+    pub fn get(&self, index: usize) -> Option<Vec<&'a T>> {
+        match self.total() {
+            Some(total) => (index < total).then(|| self.decode(index)),
+            // The product is too large to size exactly, so every
+            // representable `index` is necessarily in range.
+            None => Some(self.decode(index)),
         }
-        // If you would still need to carry, you have overflowed
-        let overflowed = carry;
-        self.completed = overflowed;
     }
 }
 
@@ -53,24 +97,78 @@ impl<'a, T> Iterator for Iter<'a, T> {
     type Item = Vec<&'a T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.completed {
+        if self.front == self.back {
             return None;
         }
 
-        let mut item = Vec::with_capacity(self.item_len());
+        let item = self.decode(self.front);
+        self.front += 1;
+
+        Some(item)
+    }
 
-        for &i in &self.state {
-            item.push(&self.items[i]);
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let target = self.front.saturating_add(n);
+        if target >= self.back {
+            self.front = self.back;
+            return None;
         }
 
-        self.increment_state();
+        let item = self.decode(target);
+        self.front = target + 1;
 
         Some(item)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        match self.total() {
+            Some(_) => (remaining, Some(remaining)),
+            // The full product has more than `usize::MAX` tuples, so `back`
+            // is only the `usize::MAX` sentinel; no upper bound is reported.
+            None => (remaining, None),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    /// # Panics
+    ///
+    /// Panics if the product's cardinality exceeds `usize::MAX`. Such
+    /// products can still be walked forward with `next`, but there is no
+    /// representable "last" tuple to start counting back from.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        assert!(
+            self.total().is_some(),
+            "next_back: cartesian product cardinality exceeds usize::MAX"
+        );
+
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.decode(self.back))
+    }
 }
 
 impl<T> FusedIterator for Iter<'_, T> {}
 
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    /// # Panics
+    ///
+    /// Panics if the product's cardinality exceeds `usize::MAX`, for the
+    /// same reason `next_back` does: there's no exact length to report.
+    fn len(&self) -> usize {
+        assert!(
+            self.total().is_some(),
+            "len: cartesian product cardinality exceeds usize::MAX"
+        );
+        self.back - self.front
+    }
+}
+
 /// Provides a `ProductWithRepeat` implementation for which the desired repeat
 /// size is known.
 ///
@@ -92,10 +190,14 @@ pub mod known_size {
 
     impl<T, A: AsRef<[T]>> ProductWithRepeat<T> for A {
         fn product_with_repeat<const REPEAT: usize>(&self) -> Iter<T, REPEAT> {
+            let items = self.as_ref();
+            // See `Iter::total` for why `back` falls back to `usize::MAX`
+            // instead of overflowing when the cardinality doesn't fit.
+            let back = super::checked_pow(items.len(), REPEAT).unwrap_or(usize::MAX);
             Iter {
-                items: self.as_ref(),
-                state: [0; REPEAT],
-                completed: false,
+                items,
+                front: 0,
+                back,
             }
         }
     }
@@ -104,29 +206,43 @@ pub mod known_size {
     pub struct Iter<'a, T, const REPEAT: usize> {
         /// The items used for the product
         items: &'a [T],
-        /// The list of indices for the items being iterated over.
-        state: [usize; REPEAT],
-        /// Whether or not all items have been iterated over.
-        completed: bool,
-    }
-
-    impl<T, const R: usize> Iter<'_, T, R> {
-        #[inline]
-        fn increment_state(&mut self) {
-            let mut carry = true;
-            for r in self.state.iter_mut().rev() {
-                // Increment current index
-                *r += 1;
-                if *r >= self.items.len() {
-                    *r = 0;
-                } else {
-                    carry = false;
-                    break;
-                }
+        /// The linear index of the next tuple `next()` will yield.
+        front: usize,
+        /// The linear index one past the last tuple `next_back()` will yield.
+        back: usize,
+    }
+
+    impl<'a, T, const REPEAT: usize> Iter<'a, T, REPEAT> {
+        /// Decodes a linear index into the tuple it represents, treating it
+        /// as a mixed-radix counter with base `items.len()` and `REPEAT`
+        /// digits.
+        fn decode(&self, mut index: usize) -> [&'a T; REPEAT] {
+            let base = self.items.len();
+            let mut digits = [0; REPEAT];
+            for digit in digits.iter_mut().rev() {
+                *digit = index % base;
+                index /= base;
+            }
+            std::array::from_fn(|i| &self.items[digits[i]])
+        }
+
+        /// The total number of tuples in the full product, i.e.
+        /// `items.len().pow(REPEAT)` — or `None` if that doesn't fit in a
+        /// `usize`.
+        fn total(&self) -> Option<usize> {
+            super::checked_pow(self.items.len(), REPEAT)
+        }
+
+        /// Returns the `index`-th tuple of the full product, without
+        /// consuming the iterator. Useful for sampling or sharding the
+        /// product across threads by index range.
+        pub fn get(&self, index: usize) -> Option<[&'a T; REPEAT]> {
+            match self.total() {
+                Some(total) => (index < total).then(|| self.decode(index)),
+                // The product is too large to size exactly, so every
+                // representable `index` is necessarily in range.
+                None => Some(self.decode(index)),
             }
-            // If you would still need to carry, you have overflowed
-            let overflowed = carry;
-            self.completed = overflowed;
         }
     }
 
@@ -134,19 +250,79 @@ pub mod known_size {
         type Item = [&'a T; REPEAT];
 
         fn next(&mut self) -> Option<Self::Item> {
-            if self.completed {
+            if self.front == self.back {
                 return None;
             }
 
-            let item = std::array::from_fn(|i| &self.items[self.state[i]]);
+            let item = self.decode(self.front);
+            self.front += 1;
 
-            self.increment_state();
+            Some(item)
+        }
+
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            let target = self.front.saturating_add(n);
+            if target >= self.back {
+                self.front = self.back;
+                return None;
+            }
+
+            let item = self.decode(target);
+            self.front = target + 1;
 
             Some(item)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.back - self.front;
+            match self.total() {
+                Some(_) => (remaining, Some(remaining)),
+                // The full product has more than `usize::MAX` tuples, so
+                // `back` is only the `usize::MAX` sentinel; no upper bound
+                // is reported.
+                None => (remaining, None),
+            }
+        }
+    }
+
+    impl<T, const REPEAT: usize> DoubleEndedIterator for Iter<'_, T, REPEAT> {
+        /// # Panics
+        ///
+        /// Panics if the product's cardinality exceeds `usize::MAX`. Such
+        /// products can still be walked forward with `next`, but there is
+        /// no representable "last" tuple to start counting back from.
+        fn next_back(&mut self) -> Option<Self::Item> {
+            assert!(
+                self.total().is_some(),
+                "next_back: cartesian product cardinality exceeds usize::MAX"
+            );
+
+            if self.front == self.back {
+                return None;
+            }
+
+            self.back -= 1;
+
+            Some(self.decode(self.back))
+        }
     }
 
     impl<T, const R: usize> FusedIterator for Iter<'_, T, R> {}
+
+    impl<T, const R: usize> ExactSizeIterator for Iter<'_, T, R> {
+        /// # Panics
+        ///
+        /// Panics if the product's cardinality exceeds `usize::MAX`, for
+        /// the same reason `next_back` does: there's no exact length to
+        /// report.
+        fn len(&self) -> usize {
+            assert!(
+                self.total().is_some(),
+                "len: cartesian product cardinality exceeds usize::MAX"
+            );
+            self.back - self.front
+        }
+    }
 }
 
 /// Generate the Cartesian product of `N_ITS` collections.
@@ -156,10 +332,13 @@ pub mod known_size {
 pub fn product<'a, T, A: AsRef<[T]>, const N_ITS: usize>(
     items: &'a [A; N_ITS],
 ) -> Product<'a, T, A, N_ITS> {
+    // See `Product::total` for why `back` falls back to `usize::MAX` instead
+    // of overflowing when the cardinality doesn't fit.
+    let back = checked_product(items.iter().map(|a| a.as_ref().len())).unwrap_or(usize::MAX);
     Product {
         items,
-        state: [0; N_ITS],
-        completed: false,
+        front: 0,
+        back,
         _t: std::marker::PhantomData,
     }
 }
@@ -173,48 +352,518 @@ pub fn product<'a, T, A: AsRef<[T]>, const N_ITS: usize>(
 pub struct Product<'a, T, A: AsRef<[T]>, const N_ITS: usize> {
     /// The iterators being iterated over to generate the cartesian product
     items: &'a [A; N_ITS],
-    /// Indices for each iterable
-    state: [usize; N_ITS],
-    /// Whether or not the iterator has completed
-    completed: bool,
+    /// The linear index of the next tuple `next()` will yield.
+    front: usize,
+    /// The linear index one past the last tuple `next_back()` will yield.
+    back: usize,
     /// Necessary for indicating that the `&'a T` references will live long
     /// enough.
     _t: std::marker::PhantomData<&'a T>,
 }
 
+impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> Product<'a, T, A, N_ITS> {
+    /// Decodes a linear index into the tuple it represents, treating it as a
+    /// mixed-radix counter using each position's own base (the length of
+    /// that position's sub-slice).
+    fn decode(&self, mut index: usize) -> [&'a T; N_ITS] {
+        let mut digits = [0; N_ITS];
+        for (i, digit) in digits.iter_mut().enumerate().rev() {
+            let base = self.items[i].as_ref().len();
+            *digit = index % base;
+            index /= base;
+        }
+        std::array::from_fn(|i| &self.items[i].as_ref()[digits[i]])
+    }
+
+    /// The total number of tuples in the full product, i.e. the product of
+    /// each position's sub-slice length — or `None` if that doesn't fit in
+    /// a `usize`.
+    fn total(&self) -> Option<usize> {
+        checked_product(self.items.iter().map(|a| a.as_ref().len()))
+    }
+
+    /// Returns the `index`-th tuple of the full product, without consuming
+    /// the iterator. Useful for sampling or sharding the product across
+    /// threads by index range.
+    pub fn get(&self, index: usize) -> Option<[&'a T; N_ITS]> {
+        match self.total() {
+            Some(total) => (index < total).then(|| self.decode(index)),
+            // The product is too large to size exactly, so every
+            // representable `index` is necessarily in range.
+            None => Some(self.decode(index)),
+        }
+    }
+}
+
 impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> Iterator for Product<'a, T, A, N_ITS> {
     type Item = [&'a T; N_ITS];
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.completed {
+        if self.front == self.back {
             return None;
         }
 
-        // For the state:  [i, j, k, ...]
-        // Yield the item: [&items[0][i], &items[1][j], &items[2][k], ...]
-        let item = std::array::from_fn(|i| &self.items[i].as_ref()[self.state[i]]);
-
-        // Had to inline the function due to lifetime issues :|
-        #[allow(unused_labels)]
-        'increment_state: {
-            let mut carry = true;
-            for (i, r) in self.state.iter_mut().enumerate().rev() {
-                // Increment current index
-                *r += 1;
-                if *r >= self.items[i].as_ref().len() {
-                    *r = 0;
-                } else {
-                    carry = false;
-                    break;
+        let item = self.decode(self.front);
+        self.front += 1;
+
+        Some(item)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let target = self.front.saturating_add(n);
+        if target >= self.back {
+            self.front = self.back;
+            return None;
+        }
+
+        let item = self.decode(target);
+        self.front = target + 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        match self.total() {
+            Some(_) => (remaining, Some(remaining)),
+            // The full product has more than `usize::MAX` tuples, so `back`
+            // is only the `usize::MAX` sentinel; no upper bound is reported.
+            None => (remaining, None),
+        }
+    }
+}
+
+impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> DoubleEndedIterator for Product<'a, T, A, N_ITS> {
+    /// # Panics
+    ///
+    /// Panics if the product's cardinality exceeds `usize::MAX`. Such
+    /// products can still be walked forward with `next`, but there is no
+    /// representable "last" tuple to start counting back from.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        assert!(
+            self.total().is_some(),
+            "next_back: cartesian product cardinality exceeds usize::MAX"
+        );
+
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.decode(self.back))
+    }
+}
+
+impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> FusedIterator for Product<'a, T, A, N_ITS> {}
+
+impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> ExactSizeIterator for Product<'a, T, A, N_ITS> {
+    /// # Panics
+    ///
+    /// Panics if the product's cardinality exceeds `usize::MAX`, for the
+    /// same reason `next_back` does: there's no exact length to report.
+    fn len(&self) -> usize {
+        assert!(
+            self.total().is_some(),
+            "len: cartesian product cardinality exceeds usize::MAX"
+        );
+        self.back - self.front
+    }
+}
+
+/// Generate the Cartesian power of any `Iterator`, cloning its items instead
+/// of borrowing them.
+///
+/// Unlike [`ProductWithRepeat::product_with_repeat`], `iter` does not need to
+/// be an already-materialized slice: it's consumed lazily, one item at a
+/// time, so it can be fed by a `map`/`filter` pipeline or any other
+/// consuming iterator. This comes at the cost of yielding owned `Vec<T>`s
+/// rather than references.
+pub fn cartesian_power<I: Iterator>(iter: I, pow: usize) -> CartesianPower<I>
+where
+    I::Item: Clone,
+{
+    CartesianPower {
+        iter: Some(iter),
+        items: Vec::new(),
+        indices: vec![0; pow],
+        completed: false,
+    }
+}
+
+/// Iterator for the Cartesian power of an arbitrary `Iterator`.
+///
+/// Created by using the [`cartesian_power`] function.
+#[derive(Clone)]
+pub struct CartesianPower<I: Iterator> {
+    /// The source iterator, buffered into `items` on demand. Set to `None`
+    /// once exhausted, at which point `items.len()` is the fixed base.
+    iter: Option<I>,
+    /// Items pulled from `iter` so far.
+    items: Vec<I::Item>,
+    /// The indices for the items being iterated over, one per position in
+    /// the output tuple.
+    indices: Vec<usize>,
+    /// Whether or not all tuples have been iterated over.
+    completed: bool,
+}
+
+impl<I: Iterator> CartesianPower<I>
+where
+    I::Item: Clone,
+{
+    /// Ensures `items[index]` is populated, pulling from `iter` as needed.
+    /// Returns `false` if `iter` is (or becomes) exhausted before reaching
+    /// that index.
+    fn buffer_upto(&mut self, index: usize) -> bool {
+        while self.items.len() <= index {
+            let Some(iter) = self.iter.as_mut() else {
+                return false;
+            };
+            match iter.next() {
+                Some(item) => self.items.push(item),
+                None => {
+                    self.iter = None;
+                    return false;
                 }
             }
-            // If you would still need to carry, you have overflowed
-            let overflowed = carry;
-            self.completed = overflowed;
+        }
+        true
+    }
+
+    #[inline]
+    fn increment_indices(&mut self) {
+        let mut carry = true;
+        for i in (0..self.indices.len()).rev() {
+            self.indices[i] += 1;
+            if self.buffer_upto(self.indices[i]) {
+                carry = false;
+                break;
+            }
+            self.indices[i] = 0;
+        }
+        // If you would still need to carry, you have overflowed
+        let overflowed = carry;
+        self.completed = overflowed;
+    }
+}
+
+impl<I: Iterator> Iterator for CartesianPower<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.completed {
+            return None;
         }
 
+        for i in 0..self.indices.len() {
+            let index = self.indices[i];
+            if !self.buffer_upto(index) {
+                self.completed = true;
+                return None;
+            }
+        }
+
+        let item = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        self.increment_indices();
+
         Some(item)
     }
 }
 
-impl<'a, T, A: AsRef<[T]>, const N_ITS: usize> FusedIterator for Product<'a, T, A, N_ITS> {}
+impl<I: Iterator> FusedIterator for CartesianPower<I> where I::Item: Clone {}
+
+/// Generates the iterator struct backing one arity of [`product_tuple!`].
+///
+/// Each arity needs its own concrete type since every position can hold a
+/// different element type, but the body is otherwise the same mixed-radix
+/// counter used by [`Product`], with each position's base taken from the
+/// length of its own slice.
+macro_rules! product_tuple_struct {
+    ($name:ident, $n:literal; $( $T:ident : $idx:tt ),+) => {
+        /// Iterator for a heterogeneous Cartesian product.
+        ///
+        /// Created by the [`product_tuple!`](crate::product_tuple) macro.
+        pub struct $name<'a, $($T),+> {
+            items: ( $( &'a [$T] ),+ ),
+            front: usize,
+            back: usize,
+        }
+
+        impl<'a, $($T),+> $name<'a, $($T),+> {
+            #[doc(hidden)]
+            pub fn new(items: ( $( &'a [$T] ),+ )) -> Self {
+                // See `total` below for why `back` falls back to
+                // `usize::MAX` instead of overflowing when the cardinality
+                // doesn't fit.
+                let back = checked_product([ $( items.$idx.len() ),+ ]).unwrap_or(usize::MAX);
+                Self {
+                    items,
+                    front: 0,
+                    back,
+                }
+            }
+
+            /// Decodes a linear index into the tuple it represents, treating
+            /// it as a mixed-radix counter using each position's own base.
+            fn decode(&self, mut index: usize) -> ( $( &'a $T ),+ ) {
+                let lens = [ $( self.items.$idx.len() ),+ ];
+                let mut digits = [0usize; $n];
+                for i in (0..$n).rev() {
+                    digits[i] = index % lens[i];
+                    index /= lens[i];
+                }
+                ( $( &self.items.$idx[digits[$idx]] ),+ )
+            }
+
+            /// The total number of tuples in the full product, i.e. the
+            /// product of each position's slice length — or `None` if that
+            /// doesn't fit in a `usize`.
+            fn total(&self) -> Option<usize> {
+                checked_product([ $( self.items.$idx.len() ),+ ])
+            }
+
+            /// Returns the `index`-th tuple of the full product, without
+            /// consuming the iterator. Useful for sampling or sharding the
+            /// product across threads by index range.
+            pub fn get(&self, index: usize) -> Option<( $( &'a $T ),+ )> {
+                match self.total() {
+                    Some(total) => (index < total).then(|| self.decode(index)),
+                    // The product is too large to size exactly, so every
+                    // representable `index` is necessarily in range.
+                    None => Some(self.decode(index)),
+                }
+            }
+        }
+
+        impl<'a, $($T),+> Iterator for $name<'a, $($T),+> {
+            type Item = ( $( &'a $T ),+ );
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+
+                let item = self.decode(self.front);
+                self.front += 1;
+
+                Some(item)
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                let target = self.front.saturating_add(n);
+                if target >= self.back {
+                    self.front = self.back;
+                    return None;
+                }
+
+                let item = self.decode(target);
+                self.front = target + 1;
+
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.back - self.front;
+                match self.total() {
+                    Some(_) => (remaining, Some(remaining)),
+                    // The full product has more than `usize::MAX` tuples,
+                    // so `back` is only the `usize::MAX` sentinel; no upper
+                    // bound is reported.
+                    None => (remaining, None),
+                }
+            }
+        }
+
+        impl<'a, $($T),+> DoubleEndedIterator for $name<'a, $($T),+> {
+            /// # Panics
+            ///
+            /// Panics if the product's cardinality exceeds `usize::MAX`.
+            /// Such products can still be walked forward with `next`, but
+            /// there is no representable "last" tuple to start counting
+            /// back from.
+            fn next_back(&mut self) -> Option<Self::Item> {
+                assert!(
+                    self.total().is_some(),
+                    "next_back: cartesian product cardinality exceeds usize::MAX"
+                );
+
+                if self.front == self.back {
+                    return None;
+                }
+
+                self.back -= 1;
+
+                Some(self.decode(self.back))
+            }
+        }
+
+        impl<'a, $($T),+> FusedIterator for $name<'a, $($T),+> {}
+
+        impl<'a, $($T),+> ExactSizeIterator for $name<'a, $($T),+> {
+            /// # Panics
+            ///
+            /// Panics if the product's cardinality exceeds `usize::MAX`,
+            /// for the same reason `next_back` does: there's no exact
+            /// length to report.
+            fn len(&self) -> usize {
+                assert!(
+                    self.total().is_some(),
+                    "len: cartesian product cardinality exceeds usize::MAX"
+                );
+                self.back - self.front
+            }
+        }
+    };
+}
+
+product_tuple_struct!(ProductTuple2, 2; A:0, B:1);
+product_tuple_struct!(ProductTuple3, 3; A:0, B:1, C:2);
+product_tuple_struct!(ProductTuple4, 4; A:0, B:1, C:2, D:3);
+product_tuple_struct!(ProductTuple5, 5; A:0, B:1, C:2, D:3, E:4);
+product_tuple_struct!(ProductTuple6, 6; A:0, B:1, C:2, D:3, E:4, F:5);
+
+/// Generate the Cartesian product of 2 to 6 `AsRef<[_]>` collections of
+/// possibly differing element types, yielding tuples of references instead
+/// of the homogeneous array [`product`] produces.
+///
+/// ```rust
+/// use product_with_repeat::product_tuple;
+///
+/// let bytes: &[u8] = &[1, 2];
+/// let strs: &[&str] = &["a", "b"];
+/// let flags: &[bool] = &[true, false];
+///
+/// let combos: Vec<_> = product_tuple!(bytes, strs, flags).collect();
+/// assert_eq!(combos.len(), 8);
+/// assert_eq!(combos[0], (&1, &"a", &true));
+/// ```
+#[macro_export]
+macro_rules! product_tuple {
+    ($a:expr, $b:expr) => {
+        $crate::ProductTuple2::new(($a.as_ref(), $b.as_ref()))
+    };
+    ($a:expr, $b:expr, $c:expr) => {
+        $crate::ProductTuple3::new(($a.as_ref(), $b.as_ref(), $c.as_ref()))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr) => {
+        $crate::ProductTuple4::new(($a.as_ref(), $b.as_ref(), $c.as_ref(), $d.as_ref()))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr) => {
+        $crate::ProductTuple5::new(($a.as_ref(), $b.as_ref(), $c.as_ref(), $d.as_ref(), $e.as_ref()))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr) => {
+        $crate::ProductTuple6::new((
+            $a.as_ref(),
+            $b.as_ref(),
+            $c.as_ref(),
+            $d.as_ref(),
+            $e.as_ref(),
+            $f.as_ref(),
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_zero_yields_single_empty_tuple() {
+        let items = [1, 2, 3];
+        let mut iter = items.product_with_repeat(0);
+        assert_eq!(iter.next(), Some(Vec::<&i32>::new()));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn empty_slice_with_repeat_yields_nothing() {
+        let items: [i32; 0] = [];
+        let mut iter = items.product_with_repeat(3);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn reverse_meets_forward() {
+        let items = [1, 2, 3];
+        let forward: Vec<_> = items.product_with_repeat(2).collect();
+        let mut reversed: Vec<_> = items.product_with_repeat(2).rev().collect();
+        reversed.reverse();
+        assert_eq!(forward, reversed);
+
+        // Interleaving `next` and `next_back` should meet in the middle
+        // without skipping or repeating a tuple.
+        let mut iter = items.product_with_repeat(2);
+        let mut collected = Vec::new();
+        loop {
+            match (iter.next(), iter.next_back()) {
+                (Some(front), Some(back)) => {
+                    collected.push(front);
+                    collected.push(back);
+                }
+                (Some(front), None) => {
+                    collected.push(front);
+                    break;
+                }
+                (None, _) => break,
+            }
+        }
+        collected.sort();
+        assert_eq!(collected, forward);
+    }
+
+    #[test]
+    fn size_hint_reflects_next_back() {
+        let items = [1, 2, 3];
+        let mut iter = items.product_with_repeat(2);
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (8, Some(8)));
+        assert_eq!(iter.count(), 8);
+    }
+
+    #[test]
+    fn nth_matches_naive_skip() {
+        let items = [1, 2, 3, 4];
+        for n in 0..20 {
+            let via_nth = items.product_with_repeat(3).nth(n);
+
+            let mut naive = items.product_with_repeat(3);
+            for _ in 0..n {
+                naive.next();
+            }
+            let via_skip = naive.next();
+
+            assert_eq!(via_nth, via_skip, "mismatch at n = {n}");
+        }
+    }
+
+    #[test]
+    fn overflowing_cardinality_still_iterates_lazily() {
+        let items = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        // 10^20 overflows `usize` on both 32- and 64-bit targets.
+        let mut iter = items.product_with_repeat(20);
+
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next(), Some(vec![&0; 20]));
+        assert_eq!(
+            iter.next(),
+            Some([&0; 19].into_iter().chain([&1]).collect::<Vec<_>>())
+        );
+
+        assert!(iter.get(0).is_some());
+        assert_eq!(iter.get(0), Some(vec![&0; 20]));
+
+        let mut panicking = items.product_with_repeat(20);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            panicking.next_back()
+        }));
+        assert!(result.is_err());
+    }
+}